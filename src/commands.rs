@@ -0,0 +1,224 @@
+use std::ffi::OsString;
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use clap::{Args, Subcommand, ValueHint};
+use regex::Regex;
+
+use crate::config::{Config, ConfigOverrides};
+use crate::output::{filter_records, sort_records, AniRecord, SortOrder};
+use crate::paths::{collect_genome_paths, collect_required_paths};
+
+#[derive(Subcommand, Debug)]
+pub enum Commands {
+    /// Collect the genomes to sketch and resolve parameters; no sketch files are computed or written
+    Sketch(SketchArgs),
+
+    /// Print the pairwise ANI table for query vs reference genomes; no ANI is computed, so the table has no rows
+    Dist(DistArgs),
+
+    /// Print the all-vs-all pairwise ANI table; no ANI is computed, so the table has no rows
+    Triangle(TriangleArgs),
+
+    /// Print the ANI table for query genomes against a sketch database; the database is not read and the table has no rows
+    Search(SearchArgs),
+
+    /// Generate shell tab-completion scripts
+    Completions {
+        /// Shell to generate completions for
+        #[arg(value_enum)]
+        shell: clap_complete::Shell,
+    },
+}
+
+/// Sketching/ANI parameters shared across subcommands, layered on top of
+/// the config file by `Config::load`.
+#[derive(Args, Debug, Default)]
+pub struct ParamArgs {
+    /// Sketch k-mer length
+    #[arg(short = 'k', long)]
+    pub k: Option<u8>,
+
+    /// Sketch compression factor
+    #[arg(short = 'c', long)]
+    pub c: Option<u32>,
+
+    /// Marker k-mer index/subsampling rate used for screening
+    #[arg(short = 'm', long)]
+    pub m: Option<u32>,
+
+    /// Minimum ANI required for a pair to be reported
+    #[arg(long)]
+    pub screen: Option<f64>,
+
+    /// Minimum aligned fraction required for a pair to be reported
+    #[arg(long)]
+    pub min_aligned_frac: Option<f64>,
+}
+
+impl From<&ParamArgs> for ConfigOverrides {
+    fn from(args: &ParamArgs) -> Self {
+        ConfigOverrides {
+            k: args.k,
+            c: args.c,
+            m: args.m,
+            screen: args.screen,
+            min_aligned_frac: args.min_aligned_frac,
+        }
+    }
+}
+
+/// Output ordering/filtering shared by the commands that print a pairwise
+/// ANI table (`dist`, `triangle`, `search`).
+#[derive(Args, Debug)]
+pub struct OutputArgs {
+    /// Order pairwise ANI results by this column
+    #[arg(long, value_enum, default_value_t = SortOrder::Ani)]
+    pub sort: SortOrder,
+
+    /// Only keep ANI results whose reference or query name matches this regex
+    #[arg(long)]
+    pub filter: Option<Regex>,
+
+    /// Write the result table here instead of stdout
+    #[arg(short, long, value_hint = ValueHint::FilePath)]
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Args, Debug)]
+pub struct SketchArgs {
+    /// Genomes to sketch. Pass `-` (or omit entirely) to read paths from STDIN.
+    #[arg(value_hint = ValueHint::AnyPath)]
+    pub inputs: Option<Vec<OsString>>,
+
+    /// Directory to write sketch files into
+    #[arg(short, long, value_hint = ValueHint::DirPath)]
+    pub output: PathBuf,
+
+    #[command(flatten)]
+    pub params: ParamArgs,
+}
+
+#[derive(Args, Debug)]
+pub struct DistArgs {
+    /// Query genomes. Pass `-` to read paths from STDIN.
+    #[arg(short, long, required = true, value_hint = ValueHint::AnyPath)]
+    pub query: Vec<OsString>,
+
+    /// Reference genomes. Pass `-` to read paths from STDIN.
+    #[arg(short, long, required = true, value_hint = ValueHint::AnyPath)]
+    pub reference: Vec<OsString>,
+
+    #[command(flatten)]
+    pub params: ParamArgs,
+
+    #[command(flatten)]
+    pub output: OutputArgs,
+}
+
+#[derive(Args, Debug)]
+pub struct TriangleArgs {
+    /// Genomes to compare all-vs-all. Pass `-` (or omit entirely) to read paths from STDIN.
+    #[arg(value_hint = ValueHint::AnyPath)]
+    pub inputs: Option<Vec<OsString>>,
+
+    #[command(flatten)]
+    pub params: ParamArgs,
+
+    #[command(flatten)]
+    pub output: OutputArgs,
+}
+
+#[derive(Args, Debug)]
+pub struct SearchArgs {
+    /// Query genomes. Pass `-` to read paths from STDIN.
+    #[arg(required = true, value_hint = ValueHint::AnyPath)]
+    pub query: Vec<OsString>,
+
+    /// Prebuilt sketch database directory to search against
+    #[arg(short, long, value_hint = ValueHint::DirPath)]
+    pub database: PathBuf,
+
+    #[command(flatten)]
+    pub params: ParamArgs,
+
+    #[command(flatten)]
+    pub output: OutputArgs,
+}
+
+/// Apply `--filter` and `--sort`, then write the resulting ANI table to
+/// `output.output` if given, or stdout otherwise.
+fn report(mut records: Vec<AniRecord>, output: &OutputArgs) -> Result<()> {
+    if let Some(pattern) = &output.filter {
+        records = filter_records(records, pattern);
+    }
+    sort_records(&mut records, output.sort);
+
+    let mut writer: Box<dyn Write> = match &output.output {
+        Some(path) => Box::new(
+            File::create(path)
+                .with_context(|| format!("failed to create output file {}", path.display()))?,
+        ),
+        None => Box::new(io::stdout()),
+    };
+
+    for record in &records {
+        writeln!(
+            writer,
+            "{}\t{}\t{:.4}\t{:.4}",
+            record.reference, record.query, record.ani, record.aligned_fraction
+        )?;
+    }
+
+    Ok(())
+}
+
+pub fn run_sketch(args: SketchArgs, config_path: Option<&PathBuf>, verbosity: u8) -> Result<()> {
+    let config = Config::load(config_path.map(|p| p.as_path()), &(&args.params).into())?;
+    let inputs = collect_genome_paths(args.inputs)?;
+    if verbosity > 0 {
+        eprintln!("[debug] resolved config: {config:?}");
+        eprintln!("[debug] inputs: {inputs:?}");
+    }
+    println!(
+        "collected {} genome(s); no sketches written to {}",
+        inputs.len(),
+        args.output.display()
+    );
+    Ok(())
+}
+
+pub fn run_dist(args: DistArgs, config_path: Option<&PathBuf>, verbosity: u8) -> Result<()> {
+    let config = Config::load(config_path.map(|p| p.as_path()), &(&args.params).into())?;
+    let query = collect_required_paths(args.query)?;
+    let reference = collect_required_paths(args.reference)?;
+    if verbosity > 0 {
+        eprintln!("[debug] resolved config: {config:?}");
+        eprintln!("[debug] query: {query:?}");
+        eprintln!("[debug] reference: {reference:?}");
+    }
+    report(Vec::new(), &args.output)
+}
+
+pub fn run_triangle(args: TriangleArgs, config_path: Option<&PathBuf>, verbosity: u8) -> Result<()> {
+    let config = Config::load(config_path.map(|p| p.as_path()), &(&args.params).into())?;
+    let inputs = collect_genome_paths(args.inputs)?;
+    if verbosity > 0 {
+        eprintln!("[debug] resolved config: {config:?}");
+        eprintln!("[debug] inputs: {inputs:?}");
+    }
+    report(Vec::new(), &args.output)
+}
+
+pub fn run_search(args: SearchArgs, config_path: Option<&PathBuf>, verbosity: u8) -> Result<()> {
+    let config = Config::load(config_path.map(|p| p.as_path()), &(&args.params).into())?;
+    let query = collect_required_paths(args.query)?;
+    if verbosity > 0 {
+        eprintln!("[debug] resolved config: {config:?}");
+        eprintln!("[debug] query: {query:?}");
+        eprintln!("[debug] database: {:?}", args.database);
+    }
+    report(Vec::new(), &args.output)
+}
@@ -0,0 +1,155 @@
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+/// Fully-resolved skani parameters, after merging defaults, an optional
+/// config file, and explicit CLI overrides (in that precedence order).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Config {
+    /// Sketch k-mer length.
+    pub k: u8,
+    /// Sketch compression factor.
+    pub c: u32,
+    /// Marker k-mer index/subsampling rate used for screening.
+    pub m: u32,
+    /// Minimum ANI required for a pair to be reported.
+    pub screen: f64,
+    /// Minimum aligned fraction required for a pair to be reported (vMAG-specific).
+    pub min_aligned_frac: f64,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            k: 15,
+            c: 30,
+            m: 1000,
+            screen: 80.0,
+            min_aligned_frac: 0.15,
+        }
+    }
+}
+
+/// On-disk representation of a config file. Every field is optional so a
+/// user only needs to specify the parameters they want to override.
+#[derive(Debug, Default, Deserialize)]
+struct ConfigFile {
+    k: Option<u8>,
+    c: Option<u32>,
+    m: Option<u32>,
+    screen: Option<f64>,
+    min_aligned_frac: Option<f64>,
+}
+
+/// Explicit CLI overrides layered on top of the config file.
+///
+/// Each field is `None` when the user didn't pass the corresponding flag,
+/// so `Config::load` can tell "not set" apart from "set to the default".
+#[derive(Debug, Default)]
+pub struct ConfigOverrides {
+    pub k: Option<u8>,
+    pub c: Option<u32>,
+    pub m: Option<u32>,
+    pub screen: Option<f64>,
+    pub min_aligned_frac: Option<f64>,
+}
+
+impl Config {
+    /// Load and resolve parameters: built-in defaults < config file < CLI overrides.
+    pub fn load(path: Option<&Path>, overrides: &ConfigOverrides) -> Result<Self> {
+        let mut config = Config::default();
+
+        if let Some(path) = path {
+            let text = fs::read_to_string(path)
+                .with_context(|| format!("failed to read config file {}", path.display()))?;
+            let file: ConfigFile = toml::from_str(&text)
+                .with_context(|| format!("failed to parse config file {}", path.display()))?;
+
+            if let Some(k) = file.k {
+                config.k = k;
+            }
+            if let Some(c) = file.c {
+                config.c = c;
+            }
+            if let Some(m) = file.m {
+                config.m = m;
+            }
+            if let Some(screen) = file.screen {
+                config.screen = screen;
+            }
+            if let Some(min_aligned_frac) = file.min_aligned_frac {
+                config.min_aligned_frac = min_aligned_frac;
+            }
+        }
+
+        if let Some(k) = overrides.k {
+            config.k = k;
+        }
+        if let Some(c) = overrides.c {
+            config.c = c;
+        }
+        if let Some(m) = overrides.m {
+            config.m = m;
+        }
+        if let Some(screen) = overrides.screen {
+            config.screen = screen;
+        }
+        if let Some(min_aligned_frac) = overrides.min_aligned_frac {
+            config.min_aligned_frac = min_aligned_frac;
+        }
+
+        Ok(config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn write_config(contents: &str) -> tempfile::NamedTempFile {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        file.write_all(contents.as_bytes()).unwrap();
+        file
+    }
+
+    #[test]
+    fn defaults_apply_when_nothing_else_is_set() {
+        let config = Config::load(None, &ConfigOverrides::default()).unwrap();
+        assert_eq!(config, Config::default());
+    }
+
+    #[test]
+    fn config_file_overrides_defaults() {
+        let file = write_config("k = 21\nscreen = 95.0\n");
+        let config = Config::load(Some(file.path()), &ConfigOverrides::default()).unwrap();
+        assert_eq!(config.k, 21);
+        assert_eq!(config.screen, 95.0);
+        // untouched fields keep their default value
+        assert_eq!(config.c, Config::default().c);
+    }
+
+    #[test]
+    fn cli_overrides_win_over_config_file() {
+        let file = write_config("k = 21\n");
+        let overrides = ConfigOverrides {
+            k: Some(13),
+            ..Default::default()
+        };
+        let config = Config::load(Some(file.path()), &overrides).unwrap();
+        assert_eq!(config.k, 13);
+    }
+
+    #[test]
+    fn cli_overrides_win_over_defaults_with_no_config_file() {
+        let overrides = ConfigOverrides {
+            min_aligned_frac: Some(0.5),
+            ..Default::default()
+        };
+        let config = Config::load(None, &overrides).unwrap();
+        assert_eq!(config.min_aligned_frac, 0.5);
+        assert_eq!(config.k, Config::default().k);
+    }
+}
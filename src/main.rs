@@ -1,40 +1,64 @@
+use std::io;
 use std::path::PathBuf;
 
-use clap::{Parser, Subcommand};
+use clap::{CommandFactory, Parser};
+
+mod commands;
+mod config;
+mod output;
+mod paths;
+mod threads;
+
+use commands::Commands;
 
 #[derive(Parser, Debug)]
 #[command(author, version, about="outer", long_about = None)]
 struct Cli {
-    /// Optional name to operate on
-    name: Option<Vec<String>>,
-
     /// Sets a custom config file
-    #[arg(short, long, value_name = "FILE")]
+    #[arg(short, long, value_name = "FILE", value_hint = clap::ValueHint::FilePath)]
     config: Option<PathBuf>,
 
     /// Turn debugging information on
     #[arg(short, long, action = clap::ArgAction::Count)]
     debug: u8,
 
-    #[command(subcommand)]
-    command: Option<Commands>,
-}
+    /// Number of threads to use (0 or unset: all logical CPUs)
+    #[arg(short, long, global = true)]
+    threads: Option<usize>,
 
-#[derive(Subcommand, Debug)]
-enum Commands {
-    /// test commands
-    Test {
-        /// lists test values
-        #[arg(short, long)]
-        list: bool,
-    },
+    #[command(subcommand)]
+    command: Commands,
 }
 
 fn main() {
     let cli = Cli::parse();
-    println!("{:?}", cli);
 
-    if let Some(name) = cli.name.as_deref() {
-        println!("{name:?}");
+    let shell = match &cli.command {
+        Commands::Completions { shell } => Some(*shell),
+        _ => None,
+    };
+    if let Some(shell) = shell {
+        let mut cmd = Cli::command();
+        let name = cmd.get_name().to_string();
+        clap_complete::generate(shell, &mut cmd, name, &mut io::stdout());
+        return;
+    }
+
+    if let Err(e) = threads::init_global_pool(cli.threads) {
+        eprintln!("error: {e:#}");
+        std::process::exit(1);
+    }
+
+    let result = match cli.command {
+        Commands::Completions { .. } => unreachable!("handled above"),
+        Commands::Sketch(args) => commands::run_sketch(args, cli.config.as_ref(), cli.debug),
+        Commands::Dist(args) => commands::run_dist(args, cli.config.as_ref(), cli.debug),
+        Commands::Triangle(args) => commands::run_triangle(args, cli.config.as_ref(), cli.debug),
+        Commands::Search(args) => commands::run_search(args, cli.config.as_ref(), cli.debug),
+    };
+
+    if let Err(e) = result {
+        eprintln!("error: {e:#}");
+        std::process::exit(1);
     }
 }
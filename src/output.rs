@@ -0,0 +1,131 @@
+use std::fmt;
+
+use clap::ValueEnum;
+use regex::Regex;
+
+/// A single pairwise ANI result row, as reported by `dist`/`search`-style output.
+#[derive(Debug, Clone)]
+pub struct AniRecord {
+    pub reference: String,
+    pub query: String,
+    pub ani: f64,
+    pub aligned_fraction: f64,
+}
+
+/// Column to order pairwise ANI results by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum SortOrder {
+    /// Sort by ANI, descending (default).
+    #[default]
+    Ani,
+    /// Sort by aligned fraction, descending.
+    AlignedFraction,
+    /// Sort by reference genome name, ascending.
+    Ref,
+    /// Sort by query genome name, ascending.
+    Query,
+}
+
+impl fmt::Display for SortOrder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.to_possible_value()
+            .expect("no skipped variants")
+            .get_name()
+            .fmt(f)
+    }
+}
+
+/// Sort `records` in place according to `order`.
+pub fn sort_records(records: &mut [AniRecord], order: SortOrder) {
+    match order {
+        SortOrder::Ani => {
+            records.sort_by(|a, b| b.ani.total_cmp(&a.ani));
+        }
+        SortOrder::AlignedFraction => {
+            records.sort_by(|a, b| b.aligned_fraction.total_cmp(&a.aligned_fraction));
+        }
+        SortOrder::Ref => {
+            records.sort_by(|a, b| a.reference.cmp(&b.reference));
+        }
+        SortOrder::Query => {
+            records.sort_by(|a, b| a.query.cmp(&b.query));
+        }
+    }
+}
+
+/// Keep only the records whose reference or query name matches `pattern`.
+pub fn filter_records(records: Vec<AniRecord>, pattern: &Regex) -> Vec<AniRecord> {
+    records
+        .into_iter()
+        .filter(|r| pattern.is_match(&r.reference) || pattern.is_match(&r.query))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(reference: &str, query: &str, ani: f64, aligned_fraction: f64) -> AniRecord {
+        AniRecord {
+            reference: reference.to_string(),
+            query: query.to_string(),
+            ani,
+            aligned_fraction,
+        }
+    }
+
+    fn names(records: &[AniRecord]) -> Vec<(&str, &str)> {
+        records
+            .iter()
+            .map(|r| (r.reference.as_str(), r.query.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn sort_by_ani_is_descending() {
+        let mut records = vec![
+            record("a", "x", 90.0, 0.5),
+            record("b", "y", 99.0, 0.5),
+            record("c", "z", 95.0, 0.5),
+        ];
+        sort_records(&mut records, SortOrder::Ani);
+        assert_eq!(names(&records), vec![("b", "y"), ("c", "z"), ("a", "x")]);
+    }
+
+    #[test]
+    fn sort_by_aligned_fraction_is_descending() {
+        let mut records = vec![
+            record("a", "x", 90.0, 0.1),
+            record("b", "y", 90.0, 0.9),
+            record("c", "z", 90.0, 0.5),
+        ];
+        sort_records(&mut records, SortOrder::AlignedFraction);
+        assert_eq!(names(&records), vec![("b", "y"), ("c", "z"), ("a", "x")]);
+    }
+
+    #[test]
+    fn sort_by_ref_and_query_are_ascending() {
+        let mut records = vec![record("b", "y", 0.0, 0.0), record("a", "z", 0.0, 0.0)];
+        sort_records(&mut records, SortOrder::Ref);
+        assert_eq!(names(&records), vec![("a", "z"), ("b", "y")]);
+
+        let mut records = vec![record("b", "y", 0.0, 0.0), record("a", "x", 0.0, 0.0)];
+        sort_records(&mut records, SortOrder::Query);
+        assert_eq!(names(&records), vec![("a", "x"), ("b", "y")]);
+    }
+
+    #[test]
+    fn filter_matches_either_reference_or_query() {
+        let records = vec![
+            record("virus_A", "contig_1", 0.0, 0.0),
+            record("contig_2", "virus_B", 0.0, 0.0),
+            record("phage_C", "contig_3", 0.0, 0.0),
+        ];
+        let pattern = Regex::new("virus").unwrap();
+        let filtered = filter_records(records, &pattern);
+        assert_eq!(
+            names(&filtered),
+            vec![("virus_A", "contig_1"), ("contig_2", "virus_B")]
+        );
+    }
+}
@@ -0,0 +1,161 @@
+use std::ffi::OsString;
+use std::io::{self, BufRead};
+use std::os::unix::ffi::OsStrExt;
+use std::path::PathBuf;
+
+use indexmap::IndexSet;
+
+/// Read newline-separated paths from `reader`, one path per line.
+///
+/// Lines are split on raw bytes rather than decoded as UTF-8, since genome
+/// filenames piped in from `find`/`ls` aren't guaranteed to be valid UTF-8.
+/// A trailing `\r` (from CRLF input) is stripped; no other trimming is done,
+/// so filenames with meaningful leading/trailing whitespace survive. Blank
+/// lines are skipped.
+fn read_paths_from<R: BufRead>(mut reader: R) -> io::Result<Vec<PathBuf>> {
+    let mut paths = Vec::new();
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+        let n = reader.read_until(b'\n', &mut buf)?;
+        if n == 0 {
+            break;
+        }
+        if buf.last() == Some(&b'\n') {
+            buf.pop();
+        }
+        if buf.last() == Some(&b'\r') {
+            buf.pop();
+        }
+        if buf.is_empty() {
+            continue;
+        }
+        paths.push(PathBuf::from(std::ffi::OsStr::from_bytes(&buf)));
+    }
+    Ok(paths)
+}
+
+/// Deduplicate `paths`, keeping the first occurrence of each.
+fn dedup_ordered(paths: impl IntoIterator<Item = PathBuf>) -> Vec<PathBuf> {
+    let seen: IndexSet<PathBuf> = paths.into_iter().collect();
+    seen.into_iter().collect()
+}
+
+/// Collect genome paths from positional CLI args and/or STDIN, deduplicating
+/// while preserving first-seen order.
+///
+/// A literal `-` or an empty positional list means "read paths from STDIN".
+/// If the arguments and STDIN together yield no paths, the current working
+/// directory is scanned instead.
+pub fn collect_genome_paths(args: Option<Vec<OsString>>) -> io::Result<Vec<PathBuf>> {
+    let args = args.unwrap_or_default();
+    let read_stdin = args.is_empty() || args.iter().any(|a| a == "-");
+
+    let mut paths: Vec<PathBuf> = args
+        .iter()
+        .filter(|a| *a != "-")
+        .map(PathBuf::from)
+        .collect();
+
+    if read_stdin {
+        let stdin = io::stdin();
+        paths.extend(read_paths_from(stdin.lock())?);
+    }
+
+    let mut paths = dedup_ordered(paths);
+
+    if paths.is_empty() {
+        for entry in std::fs::read_dir(".")? {
+            paths.push(entry?.path());
+        }
+        paths = dedup_ordered(paths);
+    }
+
+    Ok(paths)
+}
+
+/// Collect genome paths for a required input (e.g. `dist`'s `-q`/`-r`,
+/// `search`'s query): `args` must be non-empty (clap's `required = true`
+/// enforces this), so unlike [`collect_genome_paths`] there is no cwd
+/// fallback here — a required flag that resolves to nothing should be an
+/// empty result, not a silent directory scan. A literal `-` still reads
+/// paths from STDIN.
+pub fn collect_required_paths(args: Vec<OsString>) -> io::Result<Vec<PathBuf>> {
+    let read_stdin = args.iter().any(|a| a == "-");
+
+    let mut paths: Vec<PathBuf> = args
+        .iter()
+        .filter(|a| *a != "-")
+        .map(PathBuf::from)
+        .collect();
+
+    if read_stdin {
+        let stdin = io::stdin();
+        paths.extend(read_paths_from(stdin.lock())?);
+    }
+
+    Ok(dedup_ordered(paths))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn read_paths_from_skips_blank_lines_and_strips_crlf() {
+        let input = b"genome1.fna\r\n\ngenome2.fna\n".to_vec();
+        let paths = read_paths_from(Cursor::new(input)).unwrap();
+        assert_eq!(
+            paths,
+            vec![PathBuf::from("genome1.fna"), PathBuf::from("genome2.fna")]
+        );
+    }
+
+    #[test]
+    fn read_paths_from_preserves_non_utf8_bytes() {
+        let mut input = b"prefix-".to_vec();
+        input.push(0xff);
+        input.extend_from_slice(b"-suffix.fna\n");
+        let paths = read_paths_from(Cursor::new(input.clone())).unwrap();
+        assert_eq!(paths.len(), 1);
+        assert_eq!(paths[0].as_os_str().as_bytes(), &input[..input.len() - 1]);
+    }
+
+    #[test]
+    fn read_paths_from_preserves_interior_whitespace() {
+        let input = b" genome with spaces.fna \n".to_vec();
+        let paths = read_paths_from(Cursor::new(input)).unwrap();
+        assert_eq!(paths, vec![PathBuf::from(" genome with spaces.fna ")]);
+    }
+
+    #[test]
+    fn collect_required_paths_dedups_without_touching_stdin() {
+        let args = vec![
+            OsString::from("b.fna"),
+            OsString::from("a.fna"),
+            OsString::from("b.fna"),
+        ];
+        let paths = collect_required_paths(args).unwrap();
+        assert_eq!(paths, vec![PathBuf::from("b.fna"), PathBuf::from("a.fna")]);
+    }
+
+    #[test]
+    fn dedup_ordered_keeps_first_seen_order() {
+        let paths = vec![
+            PathBuf::from("b.fna"),
+            PathBuf::from("a.fna"),
+            PathBuf::from("b.fna"),
+            PathBuf::from("c.fna"),
+            PathBuf::from("a.fna"),
+        ];
+        assert_eq!(
+            dedup_ordered(paths),
+            vec![
+                PathBuf::from("b.fna"),
+                PathBuf::from("a.fna"),
+                PathBuf::from("c.fna"),
+            ]
+        );
+    }
+}
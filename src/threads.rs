@@ -0,0 +1,25 @@
+use anyhow::{ensure, Result};
+
+/// Resolve the user-requested thread count into a concrete pool size.
+///
+/// `None` or `Some(0)` means "use all logical CPUs". Anything else is taken
+/// as-is, after checking it's actually usable.
+pub fn resolve(threads: Option<usize>) -> Result<usize> {
+    let resolved = match threads {
+        None | Some(0) => std::thread::available_parallelism()?.get(),
+        Some(n) => n,
+    };
+
+    ensure!(resolved > 0, "--threads must resolve to a positive count, got {resolved}");
+
+    Ok(resolved)
+}
+
+/// Build and install the global rayon thread pool used by sketching and ANI computation.
+pub fn init_global_pool(threads: Option<usize>) -> Result<()> {
+    let num_threads = resolve(threads)?;
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(num_threads)
+        .build_global()?;
+    Ok(())
+}